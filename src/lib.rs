@@ -7,6 +7,7 @@
 ///
 use std::fs::File;
 use std::io::{BufReader, BufRead, Error};
+use std::str::FromStr;
 
 /// Reads lines from a file and provides iterators
 ///
@@ -52,16 +53,42 @@ impl InfiniteLinesReader {
     pub fn cycle(&self) -> impl Iterator<Item = &String> {
         self.lines.iter().cycle()
     }
-    
+
     pub fn iter(&self) -> impl Iterator<Item = &String> {
         self.lines.iter()
     }
-    
+
     pub fn length(&self) -> usize {
         self.lines.len()
     }
+
+    /// Streams lines from a file one at a time, so arbitrarily large inputs run in constant memory
+    pub fn stream(fname: &str) -> Result<LineStream, Error> {
+        let f = File::open(fname)?;
+        Ok(LineStream { reader: BufReader::new(f) })
+    }
+}
+
+/// Lazily yields lines read one at a time from a file
+pub struct LineStream {
+    reader: BufReader<File>,
+}
+
+impl Iterator for LineStream {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buffer = String::new();
+
+        match self.reader.read_line(&mut buffer) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(buffer.trim_end_matches('\n').to_string())),
+            Err(error) => Some(Err(error)),
+        }
+    }
 }
 
+/// Numbers the items of any iterator with `(page, line)` positions
 pub struct PagedIterator<I> {
     page_length: usize,
     page_number: usize,
@@ -93,7 +120,291 @@ impl<I> Iterator for PagedIterator<I> where I: Iterator {
     }
 }
 
-pub fn solve(fname: &str) -> Result<usize, Error> {
+/// The maximum number of cubes of each colour available in the bag.
+///
+/// A game's draws are only valid if every draw stays within these maxima.
+#[derive(Debug, Clone, Copy)]
+pub struct BagConstraints {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+
+/// A parse error anchored to the exact span of text that caused it.
+///
+/// Carries the line number (as reported by `PagedIterator`) and the failing
+/// line itself so `Display` can render a caret under the offending span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line_number: usize,
+    pub line: String,
+    pub span: std::ops::Range<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line_number: usize, line: &str, span: std::ops::Range<usize>, message: impl Into<String>) -> Self {
+        ParseError { line_number, line: line.to_string(), span, message: message.into() }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "line {}: {}", self.line_number, self.message)?;
+        writeln!(f, "{}", self.line)?;
+        write!(f, "{}{}", " ".repeat(self.span.start), "^".repeat((self.span.end - self.span.start).max(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The error returned by [`solve`] and [`ext_solve`]: either the input
+/// file could not be read, or one of its lines failed to parse.
+#[derive(Debug)]
+pub enum SolveError {
+    Io(Error),
+    Parse(ParseError),
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Io(e) => write!(f, "{}", e),
+            SolveError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<Error> for SolveError {
+    fn from(e: Error) -> Self {
+        SolveError::Io(e)
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+/// A `,`, or whitespace separates tokens without being syntactically
+/// meaningful on its own.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == ','
+}
+
+/// `:` and `;` are structural punctuation, so each is returned as its own
+/// single-character token instead of being skipped like a plain separator.
+fn is_punctuation(c: char) -> bool {
+    c == ':' || c == ';'
+}
+
+/// A position-tracking tokenizer over a single line's characters.
+pub struct Scanner<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    pos: usize,
+    line_number: usize,
+    line: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line_number: usize, line: &'a str) -> Self {
+        Scanner { chars: line.chars().peekable(), pos: 0, line_number, line }
+    }
+
+    /// Peeks at the next, not yet consumed, character without skipping
+    /// separators first.
+    pub fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Returns the next token and the byte span it spans within the line,
+    /// skipping any leading separators. A run of punctuation-free,
+    /// separator-free characters is one token; each `:` or `;` is its own
+    /// token.
+    pub fn next_token(&mut self) -> Option<(std::ops::Range<usize>, &'a str)> {
+        while matches!(self.peek(), Some(c) if is_separator(c)) {
+            self.bump();
+        }
+
+        let start = self.pos;
+        match self.peek() {
+            None => None,
+            Some(c) if is_punctuation(c) => {
+                self.bump();
+                Some((start..self.pos, &self.line[start..self.pos]))
+            }
+            Some(_) => {
+                while matches!(self.peek(), Some(c) if !is_separator(c) && !is_punctuation(c)) {
+                    self.bump();
+                }
+                Some((start..self.pos, &self.line[start..self.pos]))
+            }
+        }
+    }
+
+    /// Builds a [`ParseError`] anchored to `span` within the scanned line.
+    pub fn error(&self, span: std::ops::Range<usize>, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line_number, self.line, span, message)
+    }
+
+    /// Consumes the next token and checks that it matches `literal` exactly.
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.next_token() {
+            Some((_, tok)) if tok == literal => Ok(()),
+            Some((span, tok)) => Err(self.error(span, format!("expected {:?}, found {:?}", literal, tok))),
+            None => Err(self.error(self.pos..self.pos, format!("expected {:?}, found end of input", literal))),
+        }
+    }
+
+    /// Consumes the next token and parses it as an unsigned integer in the
+    /// given `radix` (e.g. 10 for decimal, 16 for hex).
+    pub fn parse_uint_radix(&mut self, radix: u32) -> Result<usize, ParseError> {
+        let (span, tok) = self.next_token()
+            .ok_or_else(|| self.error(self.pos..self.pos, "expected a number, found end of input"))?;
+
+        usize::from_str_radix(tok, radix)
+            .map_err(|_| self.error(span, format!("failed to parse {:?} as a base-{} number", tok, radix)))
+    }
+}
+
+/// Types that can be parsed by pulling tokens from a [`Scanner`].
+pub trait FromTokens: Sized {
+    fn from_tokens(scanner: &mut Scanner) -> Result<Self, ParseError>;
+}
+
+/// A single colour and the number of cubes of that colour shown in a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red(usize),
+    Green(usize),
+    Blue(usize),
+}
+
+impl FromTokens for Color {
+    /// Parses a single "<count> <colour>" pair of tokens, e.g. "3 blue".
+    fn from_tokens(scanner: &mut Scanner) -> Result<Self, ParseError> {
+        let value = scanner.parse_uint_radix(10)?;
+
+        let (span, colour) = scanner.next_token()
+            .ok_or_else(|| scanner.error(scanner.pos..scanner.pos, "expected a colour name, found end of input"))?;
+
+        match colour {
+            "red" => Ok(Color::Red(value)),
+            "green" => Ok(Color::Green(value)),
+            "blue" => Ok(Color::Blue(value)),
+            _ => Err(scanner.error(span, format!("unknown colour {:?}", colour))),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from_tokens(&mut Scanner::new(0, s)).map_err(|e| e.message)
+    }
+}
+
+/// One reveal of cubes from the bag, holding at most one count per colour.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Draw {
+    pub red: Option<usize>,
+    pub green: Option<usize>,
+    pub blue: Option<usize>,
+}
+
+impl FromStr for Draw {
+    type Err = String;
+
+    /// Parses a "," separated list of colour tokens, e.g. "3 blue, 4 red".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(0, s);
+        let mut draw = Draw::default();
+
+        while scanner.peek().is_some() {
+            match Color::from_tokens(&mut scanner).map_err(|e| e.message)? {
+                Color::Red(value) => draw.red = draw.red.reduce(Some(value), usize::max),
+                Color::Green(value) => draw.green = draw.green.reduce(Some(value), usize::max),
+                Color::Blue(value) => draw.blue = draw.blue.reduce(Some(value), usize::max),
+            }
+        }
+
+        Ok(draw)
+    }
+}
+
+/// A game line: an id and the draws revealed for it.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Draw>,
+}
+
+impl FromTokens for Game {
+    /// Parses a whole "Game <id>: <draw>; <draw>; ..." line.
+    fn from_tokens(scanner: &mut Scanner) -> Result<Self, ParseError> {
+        scanner.expect_literal("Game")?;
+        let id = scanner.parse_uint_radix(10)?;
+        scanner.expect_literal(":")?;
+
+        let mut draws = Vec::new();
+        let mut draw = Draw::default();
+        let mut draw_has_colours = false;
+
+        loop {
+            match scanner.peek() {
+                None => {
+                    if draw_has_colours {
+                        draws.push(draw);
+                    }
+                    break;
+                }
+                Some(';') => {
+                    scanner.next_token();
+                    if draw_has_colours {
+                        draws.push(std::mem::take(&mut draw));
+                    }
+                    draw_has_colours = false;
+                }
+                Some(_) => {
+                    match Color::from_tokens(scanner)? {
+                        Color::Red(value) => draw.red = draw.red.reduce(Some(value), usize::max),
+                        Color::Green(value) => draw.green = draw.green.reduce(Some(value), usize::max),
+                        Color::Blue(value) => draw.blue = draw.blue.reduce(Some(value), usize::max),
+                    }
+                    draw_has_colours = true;
+                }
+            }
+        }
+
+        Ok(Game { id, draws })
+    }
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Game::from_tokens(&mut Scanner::new(0, s)).map_err(|e| e.message)
+    }
+}
+
+/// Parses a whole "Game <id>: <draw>; <draw>; ..." line, producing a
+/// [`ParseError`] spanning the exact offending text on failure.
+pub fn parse_game_line(line_number: usize, line: &str) -> Result<Game, ParseError> {
+    Game::from_tokens(&mut Scanner::new(line_number, line))
+}
+
+pub fn solve(fname: &str, bag: BagConstraints) -> Result<usize, SolveError> {
 
     let reader = InfiniteLinesReader::init(fname)?;
     let mut lines = PagedIterator::init(reader.iter(), reader.length());
@@ -103,39 +414,52 @@ pub fn solve(fname: &str) -> Result<usize, Error> {
     while let Some((p, n, cv)) = lines.next() {
         println!("# processing input line {}::{} {}", p, n, cv);
 
-        let tokens: Vec<&str> = cv.split(&[' ', ',', ':', ';'][..]).map(|t| t.trim()).filter(|t| t.len() > 0).collect();
+        let game = parse_game_line(n, cv)?;
 
-        assert_eq!("Game", tokens[0]);
+        let valid = game.draws.iter().all(|draw| {
+            draw.red.unwrap_or(0) <= bag.red
+                && draw.green.unwrap_or(0) <= bag.green
+                && draw.blue.unwrap_or(0) <= bag.blue
+        });
 
-        let id = tokens[1].parse::<usize>().expect("failed to parse Game ID");
-        
-        let mut valid = true;
+        if valid {
+            println!("# game {:?} is valid", game.id);
+            rx += game.id;
+        } else {
+            println!("# game {:?} is not valid", game.id);
+        }
+    }
 
-        let mut r = 2usize;
-        while r < tokens.len() {
-            // value
-            let value = tokens[r].parse::<usize>().expect("failed to parse colour value");
-            // colour
-            let colour = tokens[r + 1];
+    println!("# result {:?}", rx);
 
-            println!("# colour: {:?} with count: {:?}", colour, value);
+    Ok(rx)
+}
 
-            //  check
-            match colour {
-                "red" => if value > 12 { valid = false; break; }
-                "green" => if value > 13 { valid = false; break; }
-                "blue" => if value > 14 { valid = false; break; }
-                _ => { panic!("failed to match colour name"); }
-            }
+/// Like `solve`, but reads `fname` through a streamed `LineStream` instead of buffering it up front; `page_length` picks how many lines are grouped into a page since a stream has no total length to report
+pub fn solve_streaming(fname: &str, bag: BagConstraints, page_length: usize) -> Result<usize, SolveError> {
 
-            r += 2;
-        };
+    let stream = InfiniteLinesReader::stream(fname)?;
+    let mut lines = PagedIterator::init(stream, page_length);
+
+    let mut rx = 0usize;
+
+    while let Some((p, n, cv)) = lines.next() {
+        let cv = cv?;
+        println!("# processing input line {}::{} {}", p, n, cv);
+
+        let game = parse_game_line(n, &cv)?;
+
+        let valid = game.draws.iter().all(|draw| {
+            draw.red.unwrap_or(0) <= bag.red
+                && draw.green.unwrap_or(0) <= bag.green
+                && draw.blue.unwrap_or(0) <= bag.blue
+        });
 
         if valid {
-            println!("# game {:?} is valid", id);
-            rx += id;
+            println!("# game {:?} is valid", game.id);
+            rx += game.id;
         } else {
-            println!("# game {:?} is not valid", id);
+            println!("# game {:?} is not valid", game.id);
         }
     }
 
@@ -168,7 +492,10 @@ impl<T> OptionExt for Option<T> {
     }
 }
 
-pub fn ext_solve(fname: &str) -> Result<usize, Error> {
+/// Part 2 does not reject games against a bag, it only computes the minimum
+/// set of cubes each game would need, but the bag is accepted here too so
+/// callers can use the same signature for both solvers.
+pub fn ext_solve(fname: &str, _bag: BagConstraints) -> Result<usize, SolveError> {
 
     let reader = InfiniteLinesReader::init(fname)?;
     let mut lines = PagedIterator::init(reader.iter(), reader.length());
@@ -178,66 +505,326 @@ pub fn ext_solve(fname: &str) -> Result<usize, Error> {
     while let Some((p, n, cv)) = lines.next() {
         println!("# processing input line {}::{} {}", p, n, cv);
 
-        let tokens: Vec<&str> = cv.split(&[' ', ',', ':', ';'][..]).map(|t| t.trim()).filter(|t| t.len() > 0).collect();
+        let game = parse_game_line(n, cv)?;
+
+        let mut red = None;
+        let mut green = None;
+        let mut blue = None;
+
+        for draw in &game.draws {
+            red = red.reduce(draw.red, usize::max);
+            green = green.reduce(draw.green, usize::max);
+            blue = blue.reduce(draw.blue, usize::max);
+        }
+
+        let game_power = red.unwrap_or(0) * green.unwrap_or(0) * blue.unwrap_or(0);
 
-        assert_eq!("Game", tokens[0]);
+        println!("# game power for min values: ({:?}, {:?}, {:?}) is {:?}", red, green, blue, game_power);
+        rx += game_power;
+    }
 
-        let mut r = 2usize;
+    println!("# result {:?}", rx);
+    Ok(rx)
+}
 
-        let mut mvalues: [Option<usize>; 3] = [None, None, None];
+/// The puzzle bag used by every registered day/part: same maxima as the
+/// puzzle statement (12 red, 13 green, 14 blue cubes).
+const PUZZLE_BAG: BagConstraints = BagConstraints { red: 12, green: 13, blue: 14 };
 
-        while r < tokens.len() {
-            // value
-            let value = tokens[r].parse::<usize>().expect("failed to parse colour value");
-            // colour
-            let colour = tokens[r + 1];
+/// A registered puzzle solver: given an input file name, returns the puzzle
+/// answer rendered as a string, or any error boxed up for display.
+pub type DayFn = fn(&str) -> Result<String, Box<dyn std::error::Error>>;
 
-            println!("# colour: {:?} with count: {:?}", colour, value);
+/// One selectable day/part, paired with the label used to pick it from the
+/// command line.
+pub struct Day {
+    pub label: &'static str,
+    pub run: DayFn,
+}
 
-            //  check
-            //
-            let index = match colour { "red" => 0, "green" => 1, "blue" => 2, _ => panic!("failed to match colour name") };
+fn day1(fname: &str) -> Result<String, Box<dyn std::error::Error>> {
+    solve(fname, PUZZLE_BAG).map(|rx| rx.to_string()).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
 
-            mvalues[index] = mvalues[index].reduce(Some(value), usize::max);
-            println!("# mvalues: {:?}", mvalues);
+fn day2(fname: &str) -> Result<String, Box<dyn std::error::Error>> {
+    ext_solve(fname, PUZZLE_BAG).map(|rx| rx.to_string()).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
 
-            r += 2;
-        };
+/// The days known to this crate, selectable by their `label`.
+pub const DAYS: &[Day] = &[
+    Day { label: "1", run: day1 },
+    Day { label: "2", run: day2 },
+];
 
-        let mut game_power: usize = 1;
-        for c in mvalues {
-            game_power *= c.unwrap_or(0);
-        }
+/// Looks up `label` in `days`, runs it against `fname`, and times it.
+///
+/// Failures are wrapped with context naming which day failed, in the style
+/// of `anyhow::Context::context`, e.g. `"error running day 2: ..."`.
+pub fn run(days: &[Day], label: &str, fname: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let day = days.iter().find(|d| d.label == label)
+        .ok_or_else(|| format!("unknown day {:?}", label))?;
 
-        println!("# game power for min values: {:?} is {:?}", mvalues, game_power);
-        rx += game_power;
-    }
+    let started = std::time::Instant::now();
 
-    println!("# result {:?}", rx);
-    Ok(rx)
+    (day.run)(fname)
+        .inspect(|_| println!("# day {} solved in {:?}", label, started.elapsed()))
+        .map_err(|e| format!("error running day {}: {}", label, e).into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const SAMPLE_BAG: BagConstraints = BagConstraints { red: 12, green: 13, blue: 14 };
+
     #[test]
     fn sample() {
-        let rx = solve("data/sample.txt").expect("failed to solve input puzzle");
+        let rx = solve("data/sample.txt", SAMPLE_BAG).expect("failed to solve input puzzle");
     }
-    
+
     #[test]
     fn puzzle() {
-        let _rx = solve("data/input.txt").expect("failed to solve input puzzle");
+        let _rx = solve("data/input.txt", SAMPLE_BAG).expect("failed to solve input puzzle");
     }
 
     #[test]
     fn ext_sample() {
-        let rx = ext_solve("data/sample.txt").expect("failed to solve input puzzle");
+        let rx = ext_solve("data/sample.txt", SAMPLE_BAG).expect("failed to solve input puzzle");
     }
 
     #[test]
     fn ext_puzzle() {
-        let rx = ext_solve("data/input.txt").expect("failed to solve input puzzle");
+        let rx = ext_solve("data/input.txt", SAMPLE_BAG).expect("failed to solve input puzzle");
+    }
+
+    #[test]
+    fn solve_respects_a_narrower_bag_constraint() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+                      Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 blue, 2 green\n\
+                      Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+                      Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+                      Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n";
+
+        let mut path = std::env::temp_dir();
+        path.push("aoc23p02_narrower_bag_sample.txt");
+        std::fs::write(&path, sample).expect("failed to write bag fixture");
+        let fname = path.to_str().expect("non-utf8 temp path");
+
+        let loose = solve(fname, SAMPLE_BAG).expect("failed to solve with the sample bag");
+
+        // Lower blue to 5: game 1's "6 blue" draw no longer fits, so it
+        // flips from valid to invalid and the total drops.
+        let narrow_bag = BagConstraints { red: 12, green: 13, blue: 5 };
+        let narrow = solve(fname, narrow_bag).expect("failed to solve with a narrower bag");
+
+        std::fs::remove_file(&path).expect("failed to remove bag fixture");
+
+        assert_eq!(loose, 8);
+        assert_eq!(narrow, 7);
+    }
+
+    #[test]
+    fn color_from_str_parses_known_colours() {
+        assert_eq!("3 blue".parse::<Color>(), Ok(Color::Blue(3)));
+        assert_eq!("12 red".parse::<Color>(), Ok(Color::Red(12)));
+        assert_eq!("0 green".parse::<Color>(), Ok(Color::Green(0)));
+    }
+
+    #[test]
+    fn color_from_str_rejects_unknown_colour() {
+        assert_eq!("3 purple".parse::<Color>(), Err("unknown colour \"purple\"".to_string()));
+    }
+
+    #[test]
+    fn draw_from_str_parses_a_comma_separated_list() {
+        let draw = "3 blue, 4 red".parse::<Draw>().expect("failed to parse draw");
+
+        assert_eq!(draw.blue, Some(3));
+        assert_eq!(draw.red, Some(4));
+        assert_eq!(draw.green, None);
+    }
+
+    #[test]
+    fn draw_from_str_accumulates_a_repeated_colour_with_max() {
+        let draw = "20 red, 3 red".parse::<Draw>().expect("failed to parse draw");
+
+        assert_eq!(draw.red, Some(20));
+    }
+
+    #[test]
+    fn game_from_str_parses_id_and_semicolon_separated_draws() {
+        let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue".parse::<Game>()
+            .expect("failed to parse game");
+
+        assert_eq!(game.id, 1);
+        assert_eq!(game.draws.len(), 2);
+        assert_eq!(game.draws[0].blue, Some(3));
+        assert_eq!(game.draws[1].green, Some(2));
+    }
+
+    #[test]
+    fn game_from_str_rejects_a_missing_game_prefix() {
+        assert_eq!(
+            "1: 3 blue".parse::<Game>().expect_err("expected a parse error"),
+            "expected \"Game\", found \"1\"".to_string()
+        );
+    }
+
+    #[test]
+    fn parse_game_line_spans_the_offending_token_on_a_missing_colon() {
+        let err = parse_game_line(7, "Game 1 3 blue").expect_err("expected a parse error");
+
+        assert_eq!(err.line_number, 7);
+        assert_eq!(err.span, 7..8);
+        assert_eq!(err.message, "expected \":\", found \"3\"");
+    }
+
+    #[test]
+    fn parse_game_line_spans_an_unknown_colour_name() {
+        let err = parse_game_line(1, "Game 1: 3 purple").expect_err("expected a parse error");
+
+        assert_eq!(err.span, 10..16);
+        assert_eq!(err.message, "unknown colour \"purple\"");
+    }
+
+    #[test]
+    fn parse_error_display_renders_a_caret_under_the_span() {
+        let err = parse_game_line(1, "Game 1 3 blue").expect_err("expected a parse error");
+
+        let rendered = err.to_string();
+        let mut lines = rendered.lines();
+
+        assert_eq!(lines.next(), Some("line 1: expected \":\", found \"3\""));
+        assert_eq!(lines.next(), Some("Game 1 3 blue"));
+        assert_eq!(lines.next(), Some("       ^"));
+    }
+
+    #[test]
+    fn solve_error_from_parse_error_displays_the_same_message() {
+        let err = parse_game_line(1, "Game 1 3 blue").expect_err("expected a parse error");
+        let solve_err: SolveError = err.clone().into();
+
+        assert_eq!(solve_err.to_string(), err.to_string());
+    }
+
+    #[test]
+    fn scanner_next_token_splits_words_and_punctuation() {
+        let mut scanner = Scanner::new(1, "Game 1: 3 blue");
+
+        assert_eq!(scanner.next_token(), Some((0..4, "Game")));
+        assert_eq!(scanner.next_token(), Some((5..6, "1")));
+        assert_eq!(scanner.next_token(), Some((6..7, ":")));
+        assert_eq!(scanner.next_token(), Some((8..9, "3")));
+        assert_eq!(scanner.next_token(), Some((10..14, "blue")));
+        assert_eq!(scanner.next_token(), None);
+    }
+
+    #[test]
+    fn scanner_expect_literal_matches_or_errors() {
+        let mut scanner = Scanner::new(1, "Game 1");
+
+        assert_eq!(scanner.expect_literal("Game"), Ok(()));
+        assert_eq!(
+            scanner.expect_literal(":"),
+            Err(scanner.error(5..6, "expected \":\", found \"1\""))
+        );
+    }
+
+    #[test]
+    fn scanner_expect_literal_errors_at_end_of_input() {
+        let mut scanner = Scanner::new(1, "");
+
+        assert_eq!(
+            scanner.expect_literal("Game"),
+            Err(scanner.error(0..0, "expected \"Game\", found end of input"))
+        );
+    }
+
+    #[test]
+    fn scanner_parse_uint_radix_supports_non_decimal_radixes() {
+        let mut scanner = Scanner::new(1, "ff 10");
+
+        assert_eq!(scanner.parse_uint_radix(16), Ok(255));
+        assert_eq!(scanner.parse_uint_radix(10), Ok(10));
+    }
+
+    #[test]
+    fn run_dispatches_a_known_label() {
+        let mut path = std::env::temp_dir();
+        path.push("aoc23p02_run_known_label.txt");
+        std::fs::write(&path, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n")
+            .expect("failed to write run fixture");
+        let fname = path.to_str().expect("non-utf8 temp path");
+
+        let answer = run(DAYS, "1", fname).expect("expected day 1 to run");
+
+        std::fs::remove_file(&path).expect("failed to remove run fixture");
+
+        assert_eq!(answer, "1");
+    }
+
+    #[test]
+    fn run_rejects_an_unknown_label() {
+        let err = run(DAYS, "99", "unused.txt").expect_err("expected an unknown day error");
+
+        assert_eq!(err.to_string(), "unknown day \"99\"");
+    }
+
+    #[test]
+    fn run_wraps_a_day_failure_with_context() {
+        let mut path = std::env::temp_dir();
+        path.push("aoc23p02_run_malformed_input.txt");
+        std::fs::write(&path, "not a game line\n").expect("failed to write run fixture");
+        let fname = path.to_str().expect("non-utf8 temp path");
+
+        let err = run(DAYS, "1", fname).expect_err("expected day 1 to fail to parse");
+
+        std::fs::remove_file(&path).expect("failed to remove run fixture");
+
+        assert!(err.to_string().starts_with("error running day 1: "));
+    }
+
+    #[test]
+    fn solve_streaming_matches_solve_over_several_pages() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+                      Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 blue, 2 green\n\
+                      Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+                      Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+                      Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n";
+
+        let mut path = std::env::temp_dir();
+        path.push("aoc23p02_solve_streaming_sample.txt");
+        std::fs::write(&path, sample).expect("failed to write streaming sample fixture");
+        let fname = path.to_str().expect("non-utf8 temp path");
+
+        // A page_length smaller than the input forces more than one page,
+        // exercising the pagination a LineStream (with no known total
+        // length) still has to go through.
+        let streamed = solve_streaming(fname, SAMPLE_BAG, 2).expect("failed to stream input");
+        let buffered = solve(fname, SAMPLE_BAG).expect("failed to solve input");
+
+        std::fs::remove_file(&path).expect("failed to remove streaming sample fixture");
+
+        assert_eq!(streamed, 8);
+        assert_eq!(streamed, buffered);
+    }
+
+    #[test]
+    fn game_from_str_ignores_a_stray_empty_draw() {
+        let game = "Game 1: 1 red;;2 blue".parse::<Game>().expect("failed to parse game");
+
+        assert_eq!(game.draws.len(), 2);
+        assert_eq!(game.draws[0].red, Some(1));
+        assert_eq!(game.draws[1].blue, Some(2));
+    }
+
+    #[test]
+    fn scanner_parse_uint_radix_rejects_malformed_numbers() {
+        let mut scanner = Scanner::new(1, "xyz");
+
+        assert_eq!(
+            scanner.parse_uint_radix(10),
+            Err(scanner.error(0..3, "failed to parse \"xyz\" as a base-10 number"))
+        );
     }
 }
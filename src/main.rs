@@ -0,0 +1,27 @@
+use std::env;
+use std::process::ExitCode;
+
+use aoc23p02::{run, DAYS};
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let (label, fname) = match (args.next(), args.next()) {
+        (Some(label), Some(fname)) => (label, fname),
+        _ => {
+            eprintln!("usage: aoc23p02 <day> <input-file>");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(DAYS, &label, &fname) {
+        Ok(answer) => {
+            println!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}